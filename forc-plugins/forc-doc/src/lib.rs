@@ -0,0 +1,54 @@
+//! `forc-doc`: a rustdoc-like static documentation generator for Sway programs.
+
+mod doc;
+mod render;
+
+/// Flags controlling what a single doc build emits, threaded through every
+/// [`render::Renderable::render`] call.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct RenderPlan {
+    /// Emit a browsable `src/` tree and per-item "source" links.
+    pub(crate) include_sources: bool,
+}
+impl RenderPlan {
+    pub(crate) fn new(include_sources: bool) -> Self {
+        Self { include_sources }
+    }
+}
+
+/// Emits the build-wide outputs that aren't tied to a single item page: the
+/// search index, the settings page, the browsable `src/` tree (when
+/// `render_plan.include_sources` is set), and the static JS/CSS/image assets
+/// they all depend on. Called once per doc build, after every item page has
+/// been rendered and written.
+pub(crate) fn finalize_build(
+    doc_root: &std::path::Path,
+    render_plan: RenderPlan,
+    search_index: &render::search::SearchIndex,
+    settings_page: render::settings::SettingsPage,
+    source_pages: Vec<render::sources::SourcePage>,
+) -> anyhow::Result<()> {
+    use render::Renderable;
+
+    std::fs::write(
+        doc_root.join("search-index.js"),
+        search_index.render()?,
+    )?;
+    std::fs::write(
+        doc_root.join("settings.html"),
+        settings_page.render(render_plan.clone())?.into_string()?,
+    )?;
+
+    if render_plan.include_sources {
+        for page in source_pages {
+            let path = doc_root.join(page.output_path());
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            let rendered = page.render(render_plan.clone())?.into_string()?;
+            std::fs::write(path, rendered)?;
+        }
+    }
+
+    render::assets::write_static_assets(doc_root)
+}
@@ -0,0 +1,34 @@
+//! Copies the static JS/CSS/image assets the renderer links to (embedded
+//! into the binary via `include_str!`/`include_bytes!`) into a doc build's
+//! `assets/` directory.
+
+use anyhow::Result;
+use std::{fs, path::Path};
+
+const TEXT_ASSETS: &[(&str, &str)] = &[
+    ("search.js", include_str!("search.js")),
+    ("storage.js", include_str!("storage.js")),
+    ("settings.js", include_str!("settings.js")),
+    ("scrape-examples.js", include_str!("scrape-examples.js")),
+    ("source-highlight.js", include_str!("source-highlight.js")),
+    ("dark.css", include_str!("dark.css")),
+    ("light.css", include_str!("light.css")),
+];
+
+const BINARY_ASSETS: &[(&str, &[u8])] = &[("wheel.svg", include_bytes!("wheel.svg"))];
+
+/// Writes every asset in [`TEXT_ASSETS`] and [`BINARY_ASSETS`] into
+/// `<doc_root>/assets/`, creating the directory if needed. Other assets the
+/// pages link to (e.g. `swaydoc.css`, `ayu.css`, `highlight.js`) are not
+/// written here.
+pub(crate) fn write_static_assets(doc_root: &Path) -> Result<()> {
+    let assets_dir = doc_root.join("assets");
+    fs::create_dir_all(&assets_dir)?;
+    for (name, contents) in TEXT_ASSETS {
+        fs::write(assets_dir.join(name), contents)?;
+    }
+    for (name, contents) in BINARY_ASSETS {
+        fs::write(assets_dir.join(name), contents)?;
+    }
+    Ok(())
+}
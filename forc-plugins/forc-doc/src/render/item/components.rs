@@ -1,15 +1,21 @@
 use crate::{
     doc::module::ModuleInfo,
     render::{
-        constant::IDENTITY, item::context::ItemContext, sidebar::*, title::DocBlockTitle, DocStyle,
-        Renderable,
+        constant::IDENTITY,
+        item::{
+            context::ItemContext,
+            examples::{self, ScrapedExample},
+        },
+        search::SearchIndexEntry,
+        settings, sidebar::*, sources,
+        title::DocBlockTitle, DocStyle, Renderable,
     },
     RenderPlan,
 };
 use anyhow::Result;
 use horrorshow::{box_html, Raw, RenderBox};
 use sway_core::language::ty::TyDecl;
-use sway_types::BaseIdent;
+use sway_types::{BaseIdent, Spanned};
 
 /// All necessary components to render the header portion of
 /// the item html doc.
@@ -31,8 +37,10 @@ impl Renderable for ItemHeader {
         let favicon = module_info.to_html_shorthand_path_string("assets/sway-logo.svg");
         let normalize = module_info.to_html_shorthand_path_string("assets/normalize.css");
         let swaydoc = module_info.to_html_shorthand_path_string("assets/swaydoc.css");
-        let ayu = module_info.to_html_shorthand_path_string("assets/ayu.css");
         let ayu_hjs = module_info.to_html_shorthand_path_string("assets/ayu.min.css");
+        let search_index = module_info.to_html_shorthand_path_string("search-index.js");
+        let storage_js = module_info.to_html_shorthand_path_string("assets/storage.js");
+        let theme_links = settings::theme_links(&module_info);
 
         Ok(box_html! {
             head {
@@ -51,8 +59,13 @@ impl Renderable for ItemHeader {
                 title: format!("{} in {} - Sway", item_name.as_str(), module_info.location());
                 link(rel="stylesheet", type="text/css", href=normalize);
                 link(rel="stylesheet", type="text/css", href=swaydoc, id="mainThemeStyle");
-                link(rel="stylesheet", type="text/css", href=ayu);
+                @ for (href, id, disabled) in &theme_links {
+                    link(rel="stylesheet", type="text/css", href=href, id=format!("theme-{id}"), disabled?=*disabled);
+                }
                 link(rel="stylesheet", href=ayu_hjs);
+                // applies the saved theme before the stylesheets above paint, to avoid a flash
+                script(src=storage_js);
+                script(src=search_index);
                 // TODO: Add links for fonts
             }
         })
@@ -73,6 +86,9 @@ pub(crate) struct ItemBody {
     pub(crate) code_str: String,
     pub(crate) attrs_opt: Option<String>,
     pub(crate) item_context: ItemContext,
+    /// Real call sites of this item found elsewhere in the compiled program,
+    /// already deduped and capped by [`examples::scrape_examples`].
+    pub(crate) examples: Vec<ScrapedExample>,
 }
 impl SidebarNav for ItemBody {
     fn sidebar(&self) -> Sidebar {
@@ -88,6 +104,49 @@ impl SidebarNav for ItemBody {
         )
     }
 }
+impl ItemBody {
+    /// Builds an `ItemBody`, scraping `scopes` for real call sites of
+    /// `ty_decl` to populate `examples`.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        module_info: ModuleInfo,
+        ty_decl: TyDecl,
+        item_name: BaseIdent,
+        code_str: String,
+        attrs_opt: Option<String>,
+        item_context: ItemContext,
+        scopes: &[examples::Scope],
+    ) -> Self {
+        let examples = examples::scrape_examples(&ty_decl, scopes);
+        Self {
+            module_info,
+            ty_decl,
+            item_name,
+            code_str,
+            attrs_opt,
+            item_context,
+            examples,
+        }
+    }
+
+    /// The entry the build driver should push onto the shared `SearchIndex`
+    /// for this item, once per rendered page.
+    pub(crate) fn search_index_entry(&self) -> SearchIndexEntry {
+        SearchIndexEntry::from(self)
+    }
+
+    /// The href of the "source" link shown next to the item's name, or
+    /// `None` when the doc build doesn't ship source pages or the item's
+    /// span has no backing file (e.g. it's a built-in).
+    fn source_link(module_info: &ModuleInfo, ty_decl: &TyDecl, render_plan: &RenderPlan) -> Option<String> {
+        if !render_plan.include_sources {
+            return None;
+        }
+        let span = ty_decl.span();
+        let file_name = span.path()?.file_name()?.to_str()?.to_string();
+        Some(sources::source_href(module_info, &file_name, &span))
+    }
+}
 impl Renderable for ItemBody {
     /// HTML body component
     fn render(self, render_plan: RenderPlan) -> Result<Box<dyn RenderBox>> {
@@ -99,14 +158,21 @@ impl Renderable for ItemBody {
             code_str,
             attrs_opt,
             item_context,
+            examples,
         } = self;
 
         let decl_ty = ty_decl.doc_name();
         let block_title = ty_decl.as_block_title();
+        let source_link = Self::source_link(&module_info, &ty_decl, &render_plan);
         let sidebar = sidebar.render(render_plan.clone())?;
         let item_context = (item_context.context_opt.is_some())
             .then(|| -> Result<Box<dyn RenderBox>> { item_context.render(render_plan.clone()) });
+        let rendered_examples = examples::render_examples(&module_info, &examples);
+        let scrape_examples_js = (!examples.is_empty())
+            .then(|| module_info.to_html_shorthand_path_string("assets/scrape-examples.js"));
         let sway_hjs = module_info.to_html_shorthand_path_string("assets/highlight.js");
+        let search_js = module_info.to_html_shorthand_path_string("assets/search.js");
+        let settings_href = module_info.to_html_shorthand_path_string("settings.html");
         let rendered_module_anchors = module_info.get_anchors()?;
 
         Ok(box_html! {
@@ -115,27 +181,31 @@ impl Renderable for ItemBody {
                 // this is the main code block
                 main {
                     div(class="width-limiter") {
-                        // div(class="sub-container") {
-                        //     nav(class="sub") {
-                        //         form(class="search-form") {
-                        //             div(class="search-container") {
-                        //                 span;
-                        //                 input(
-                        //                     class="search-input",
-                        //                     name="search",
-                        //                     autocomplete="off",
-                        //                     spellcheck="false",
-                        //                     // TODO: https://github.com/FuelLabs/sway/issues/3480
-                        //                     placeholder="Searchbar unimplemented, see issue #3480...",
-                        //                     type="search"
-                        //                 );
-                        //                 div(id="help-button", title="help", tabindex="-1") {
-                        //                     button(type="button") { : "?" }
-                        //                 }
-                        //             }
-                        //         }
-                        //     }
-                        // }
+                        div(class="sub-container") {
+                            nav(class="sub") {
+                                form(class="search-form") {
+                                    div(class="search-container") {
+                                        span;
+                                        input(
+                                            id="search-input",
+                                            class="search-input",
+                                            name="search",
+                                            autocomplete="off",
+                                            spellcheck="false",
+                                            placeholder="Search the docs...",
+                                            type="search"
+                                        );
+                                        div(id="help-button", title="help", tabindex="-1") {
+                                            button(type="button") { : "?" }
+                                        }
+                                        a(id="settings-menu", href=settings_href, title="settings") {
+                                            img(src=module_info.to_html_shorthand_path_string("assets/wheel.svg"), alt="Change settings");
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        div(id="search", class="search-results hidden");
                         section(id="main-content", class="content") {
                             div(class="main-heading") {
                                 h1(class="fqn") {
@@ -148,6 +218,9 @@ impl Renderable for ItemBody {
                                             : item_name.as_str();
                                         }
                                     }
+                                    @ if let Some(source_link) = &source_link {
+                                        a(class="source", href=source_link) { : "source" }
+                                    }
                                 }
                             }
                             div(class="docblock item-decl") {
@@ -170,6 +243,9 @@ impl Renderable for ItemBody {
                             @ if item_context.is_some() {
                                 : item_context.unwrap();
                             }
+                            @ if let Some(rendered_examples) = rendered_examples {
+                                : rendered_examples;
+                            }
                         }
                     }
                 }
@@ -177,6 +253,10 @@ impl Renderable for ItemBody {
                 script {
                     : "hljs.highlightAll();";
                 }
+                script(src=search_js);
+                @ if let Some(scrape_examples_js) = &scrape_examples_js {
+                    script(src=scrape_examples_js);
+                }
             }
         })
     }
@@ -0,0 +1,266 @@
+//! Scrapes real call sites of each documented item out of the compiled
+//! program and renders them as the "Examples found in repository" section
+//! on that item's page.
+
+use crate::{doc::module::ModuleInfo, render::sources};
+use horrorshow::{box_html, RenderBox};
+use sway_core::language::ty::{self, TyAstNodeContent, TyExpressionVariant};
+use sway_types::{Span, Spanned};
+
+/// One call site found for a documented item, already trimmed down to the
+/// lines worth displaying.
+#[derive(Clone, Debug)]
+pub(crate) struct ScrapedExample {
+    pub(crate) file_name: String,
+    pub(crate) call_span: Span,
+    /// The name of the function the call site lives in (or `"<module>"` for
+    /// a call at module scope), used to dedupe multiple calls from the same
+    /// function down to a single example.
+    pub(crate) enclosing_fn: String,
+    /// The snippet of source surrounding the call.
+    pub(crate) context: String,
+    /// 1-indexed `(start, end)` line numbers `context` covers in `file_name`.
+    pub(crate) context_lines: (usize, usize),
+}
+
+/// One function's worth of already-resolved AST nodes to scan for call
+/// sites, paired with that function's name (or `"<module>"` for top-level
+/// nodes). The build driver supplies one `Scope` per function body plus one
+/// for each module's top-level nodes.
+pub(crate) struct Scope<'a> {
+    pub(crate) enclosing_fn: &'a str,
+    pub(crate) nodes: &'a [ty::TyAstNode],
+}
+
+/// Dedupes call sites that land in the same function body: keeps only the
+/// earliest call site (by line) per `(file, enclosing_fn)` pair, so a
+/// function that calls the target several times contributes a single example
+/// rather than one per call. The result is sorted by file, then function name.
+pub(crate) fn dedupe_examples(mut examples: Vec<ScrapedExample>) -> Vec<ScrapedExample> {
+    examples.sort_by(|a, b| {
+        a.file_name
+            .cmp(&b.file_name)
+            .then(a.enclosing_fn.cmp(&b.enclosing_fn))
+            .then(a.context_lines.cmp(&b.context_lines))
+    });
+    examples.dedup_by(|a, b| a.file_name == b.file_name && a.enclosing_fn == b.enclosing_fn);
+    examples
+}
+
+/// The most examples kept for a single item.
+const MAX_EXAMPLES: usize = 5;
+
+/// Walks every scope in the compiled program and records the spans where
+/// `target` is called by name, each trimmed down to the surrounding lines
+/// worth displaying. Only function calls are found, and only where
+/// [`collect_call_sites_in_expr`] recurses; other references to `target`
+/// (e.g. a struct instantiation) are not recorded. Dedupes down to one
+/// example per calling function via [`dedupe_examples`], then keeps at most
+/// [`MAX_EXAMPLES`].
+pub(crate) fn scrape_examples(target: &ty::TyDecl, scopes: &[Scope]) -> Vec<ScrapedExample> {
+    let Some(target_name) = target.get_decl_ident().map(|ident| ident.as_str().to_string())
+    else {
+        return Vec::new();
+    };
+
+    let mut sites = Vec::new();
+    for scope in scopes {
+        for node in scope.nodes {
+            collect_call_sites(&target_name, node, scope.enclosing_fn, &mut sites);
+        }
+    }
+    let mut examples = dedupe_examples(sites);
+    examples.truncate(MAX_EXAMPLES);
+    examples
+}
+
+/// Looks for calls whose callee's name matches `target_name` in `node`, when
+/// it is an expression, an implicit return or a variable declaration's
+/// initializer; other kinds of node are skipped.
+fn collect_call_sites(
+    target_name: &str,
+    node: &ty::TyAstNode,
+    enclosing_fn: &str,
+    sites: &mut Vec<ScrapedExample>,
+) {
+    match &node.content {
+        TyAstNodeContent::Expression(expr) | TyAstNodeContent::ImplicitReturnExpression(expr) => {
+            collect_call_sites_in_expr(target_name, expr, enclosing_fn, sites);
+        }
+        TyAstNodeContent::Declaration(ty::TyDecl::VariableDecl(decl)) => {
+            collect_call_sites_in_expr(target_name, &decl.body, enclosing_fn, sites);
+        }
+        _ => {}
+    }
+}
+
+/// Recurses through `expr`'s subexpressions looking for calls to
+/// `target_name`: call arguments, code-block statements, `if` conditions and
+/// branches, lazy-operator operands and tuple elements. Other expression
+/// kinds (e.g. `match`, loops, struct fields) are not searched.
+fn collect_call_sites_in_expr(
+    target_name: &str,
+    expr: &ty::TyExpression,
+    enclosing_fn: &str,
+    sites: &mut Vec<ScrapedExample>,
+) {
+    if let TyExpressionVariant::FunctionApplication {
+        call_path,
+        arguments,
+        ..
+    } = &expr.expression
+    {
+        if call_path.suffix.as_str() == target_name {
+            if let Some(site) = scraped_example_for(&expr.span(), enclosing_fn) {
+                sites.push(site);
+            }
+        }
+        for (_, argument) in arguments {
+            collect_call_sites_in_expr(target_name, argument, enclosing_fn, sites);
+        }
+        return;
+    }
+
+    match &expr.expression {
+        TyExpressionVariant::CodeBlock(block) => {
+            for node in &block.contents {
+                collect_call_sites(target_name, node, enclosing_fn, sites);
+            }
+        }
+        TyExpressionVariant::IfExp {
+            condition,
+            then,
+            r#else,
+        } => {
+            collect_call_sites_in_expr(target_name, condition, enclosing_fn, sites);
+            collect_call_sites_in_expr(target_name, then, enclosing_fn, sites);
+            if let Some(r#else) = r#else {
+                collect_call_sites_in_expr(target_name, r#else, enclosing_fn, sites);
+            }
+        }
+        TyExpressionVariant::LazyOperator { lhs, rhs, .. } => {
+            collect_call_sites_in_expr(target_name, lhs, enclosing_fn, sites);
+            collect_call_sites_in_expr(target_name, rhs, enclosing_fn, sites);
+        }
+        TyExpressionVariant::Tuple(fields) => {
+            for field in fields {
+                collect_call_sites_in_expr(target_name, field, enclosing_fn, sites);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// How many lines of surrounding context to include above and below a call
+/// site in its rendered snippet.
+const CONTEXT_PADDING: usize = 2;
+
+/// Builds a [`ScrapedExample`] for a call found at `span`, or `None` if the
+/// span has no backing file (e.g. it originates from a built-in).
+fn scraped_example_for(span: &Span, enclosing_fn: &str) -> Option<ScrapedExample> {
+    let file_name = span.path()?.file_name()?.to_str()?.to_string();
+    let source = span.src().text.as_str();
+    let lines: Vec<&str> = source.lines().collect();
+
+    let (call_start, call_end) = sources::line_range(span);
+    let start = call_start.saturating_sub(CONTEXT_PADDING).max(1);
+    let end = (call_end + CONTEXT_PADDING).min(lines.len());
+    let context = lines[start - 1..end].join("\n");
+
+    Some(ScrapedExample {
+        file_name,
+        call_span: span.clone(),
+        enclosing_fn: enclosing_fn.to_string(),
+        context,
+        context_lines: (start, end),
+    })
+}
+
+/// Renders the "Examples found in repository" block, or `None` when no call
+/// sites were found so `ItemBody` can skip the section entirely. Only the
+/// first example is visible by default; `scrape-examples.js` toggles which
+/// one is `.active` (and therefore visible, per the inline stylesheet below)
+/// as the reader steps through the prev/next widget.
+pub(crate) fn render_examples(
+    module_info: &ModuleInfo,
+    examples: &[ScrapedExample],
+) -> Option<Box<dyn RenderBox + 'static>> {
+    if examples.is_empty() {
+        return None;
+    }
+    let examples: Vec<_> = examples
+        .iter()
+        .map(|example| {
+            let href = sources::source_href(module_info, &example.file_name, &example.call_span);
+            (example.file_name.clone(), example.context.clone(), href)
+        })
+        .collect();
+
+    Some(box_html! {
+        details(class="swaydoc-toggle example-toggle", open) {
+            summary(class="hideme") {
+                span { : "Examples found in repository" }
+            }
+            style {
+                : ".scraped-example-list .scraped-example { display: none; } \
+                   .scraped-example-list .scraped-example.active { display: block; }"
+            }
+            div(class="scraped-example-list") {
+                @ for (i, (file_name, context, href)) in examples.iter().enumerate() {
+                    div(class=if i == 0 { "scraped-example active" } else { "scraped-example" }, data-index=i) {
+                        div(class="example-links") {
+                            a(href=href) { : file_name.clone() }
+                        }
+                        pre(class="src-example sway") {
+                            code { : context.clone() }
+                        }
+                    }
+                }
+                @ if examples.len() > 1 {
+                    div(class="example-nav") {
+                        button(class="prev-example", type="button") { : "< prev" }
+                        span(class="example-count") { : format!("1 / {}", examples.len()) }
+                        button(class="next-example", type="button") { : "next >" }
+                    }
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example(file_name: &str, enclosing_fn: &str, lines: (usize, usize)) -> ScrapedExample {
+        ScrapedExample {
+            file_name: file_name.to_string(),
+            call_span: Span::dummy(),
+            enclosing_fn: enclosing_fn.to_string(),
+            context: String::new(),
+            context_lines: lines,
+        }
+    }
+
+    #[test]
+    fn dedupe_keeps_one_example_per_calling_function() {
+        let examples = vec![
+            example("main.sw", "foo", (1, 3)),
+            example("main.sw", "foo", (10, 13)),
+            example("main.sw", "bar", (20, 23)),
+        ];
+        let deduped = dedupe_examples(examples);
+        assert_eq!(deduped.len(), 2);
+        assert_eq!(deduped[0].enclosing_fn, "bar");
+        assert_eq!(deduped[1].enclosing_fn, "foo");
+    }
+
+    #[test]
+    fn dedupe_keeps_same_function_name_in_different_files() {
+        let examples = vec![
+            example("a.sw", "foo", (1, 3)),
+            example("b.sw", "foo", (1, 3)),
+        ];
+        assert_eq!(dedupe_examples(examples).len(), 2);
+    }
+}
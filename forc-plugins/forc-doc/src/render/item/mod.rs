@@ -0,0 +1,5 @@
+//! Components for rendering a single documented item's page.
+
+pub(crate) mod components;
+pub(crate) mod context;
+pub(crate) mod examples;
@@ -0,0 +1,32 @@
+//! Rendering: turns the doc-info structs gathered while walking the typed
+//! program into the on-disk HTML/CSS/JS that makes up a doc build.
+
+pub(crate) mod assets;
+pub(crate) mod constant;
+pub(crate) mod item;
+pub(crate) mod search;
+pub(crate) mod settings;
+pub(crate) mod sidebar;
+pub(crate) mod sources;
+pub(crate) mod title;
+
+use crate::RenderPlan;
+use anyhow::Result;
+use horrorshow::RenderBox;
+use sway_types::BaseIdent;
+use title::DocBlockTitle;
+
+/// Implemented by every HTML component so it can be turned into markup,
+/// consulting whichever [`RenderPlan`] flags are in effect for this build.
+pub(crate) trait Renderable {
+    fn render(self, render_plan: RenderPlan) -> Result<Box<dyn RenderBox>>;
+}
+
+/// Which kind of page is being rendered, used to build the sidebar/breadcrumbs.
+#[derive(Clone, Debug)]
+pub(crate) enum DocStyle {
+    Item {
+        title: Option<DocBlockTitle>,
+        name: Option<BaseIdent>,
+    },
+}
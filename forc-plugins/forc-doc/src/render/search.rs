@@ -0,0 +1,163 @@
+//! Builds the client-side search index consumed by `search.js`, the
+//! equivalent of rustdoc's `search-index.js`.
+
+use crate::{doc::module::ModuleInfo, render::item::components::ItemBody};
+use serde::Serialize;
+
+/// One searchable item: enough to render a result row and link to the item's
+/// page without re-reading any HTML.
+#[derive(Clone, Debug, Serialize)]
+pub(crate) struct SearchIndexEntry {
+    /// The item's name, e.g. `MyStruct`.
+    pub(crate) name: String,
+    /// The item's kind, e.g. `struct`, `enum`, `function`.
+    pub(crate) kind: &'static str,
+    /// The `::`-joined path of the module the item lives in, e.g. `my_project::my_module`.
+    pub(crate) parent: String,
+    /// A short, plain-text description taken from the item's doc attributes, if any.
+    pub(crate) description: String,
+    /// The href of the item's page, relative to the docs root.
+    pub(crate) href: String,
+}
+impl SearchIndexEntry {
+    pub(crate) fn new(
+        name: String,
+        kind: &'static str,
+        module_info: &ModuleInfo,
+        attrs_opt: &Option<String>,
+        href: String,
+    ) -> Self {
+        Self {
+            name,
+            kind,
+            parent: module_info.location(),
+            description: short_description(attrs_opt),
+            href,
+        }
+    }
+}
+impl From<&ItemBody> for SearchIndexEntry {
+    fn from(item_body: &ItemBody) -> Self {
+        let href = root_relative_href(
+            &item_body.module_info.location(),
+            &format!("{}.html", item_body.item_name.as_str()),
+        );
+        Self::new(
+            item_body.item_name.as_str().to_string(),
+            item_body.ty_decl.doc_name(),
+            &item_body.module_info,
+            &item_body.attrs_opt,
+            href,
+        )
+    }
+}
+
+/// Builds the root-relative href for a page at `file_name` inside the module
+/// at `location` (a `::`-joined path as returned by [`ModuleInfo::location`]).
+///
+/// This must NOT go through [`ModuleInfo::to_html_shorthand_path_string`],
+/// which resolves relative to whichever page is currently being rendered:
+/// `search-index.js` is a single file loaded from pages at varying depths, so
+/// every href it carries has to resolve the same way regardless of where the
+/// search was run from.
+fn root_relative_href(location: &str, file_name: &str) -> String {
+    let module_path = location.replace("::", "/");
+    if module_path.is_empty() {
+        file_name.to_string()
+    } else {
+        format!("{module_path}/{file_name}")
+    }
+}
+
+/// Caps how long a `short_description` can be when there's no sentence break
+/// to truncate at.
+const MAX_DESCRIPTION_LEN: usize = 160;
+
+/// Takes a short, plain-text excerpt of a rendered doc-attribute block, to
+/// keep the index small: whole HTML tags (including their attributes) are
+/// dropped, then the text is cut at the first sentence break (". "), or at
+/// [`MAX_DESCRIPTION_LEN`] characters if there isn't one nearby.
+fn short_description(attrs_opt: &Option<String>) -> String {
+    let Some(attrs) = attrs_opt else {
+        return String::new();
+    };
+
+    let mut text = String::with_capacity(attrs.len());
+    let mut in_tag = false;
+    for c in attrs.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    let text = text.trim();
+
+    match text.find(". ") {
+        Some(end) if end < MAX_DESCRIPTION_LEN => text[..end].to_string(),
+        _ => text.chars().take(MAX_DESCRIPTION_LEN).collect(),
+    }
+}
+
+/// The full index for a doc build: every item collected while rendering,
+/// ready to be serialized to `search-index.js`.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct SearchIndex {
+    entries: Vec<SearchIndexEntry>,
+}
+impl SearchIndex {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn push(&mut self, entry: SearchIndexEntry) {
+        self.entries.push(entry);
+    }
+
+    /// Renders the index as the contents of `search-index.js`: a single
+    /// `var` assignment so it can be loaded with a plain `<script src=..>`
+    /// tag, no module system required.
+    pub(crate) fn render(&self) -> anyhow::Result<String> {
+        let json = serde_json::to_string(&self.entries)?;
+        Ok(format!("var searchIndex = {json};"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn root_relative_href_nests_under_module_path() {
+        assert_eq!(
+            root_relative_href("my_project::my_module", "MyStruct.html"),
+            "my_project/my_module/MyStruct.html"
+        );
+    }
+
+    #[test]
+    fn root_relative_href_at_project_root() {
+        assert_eq!(
+            root_relative_href("my_project", "MyStruct.html"),
+            "my_project/MyStruct.html"
+        );
+    }
+
+    #[test]
+    fn short_description_strips_whole_tags() {
+        let attrs = Some("<p>A <strong>great</strong> struct.</p>".to_string());
+        assert_eq!(short_description(&attrs), "A great struct.");
+    }
+
+    #[test]
+    fn short_description_cuts_at_first_sentence() {
+        let attrs = Some("First sentence. Second sentence.".to_string());
+        assert_eq!(short_description(&attrs), "First sentence");
+    }
+
+    #[test]
+    fn short_description_empty_when_no_attrs() {
+        assert_eq!(short_description(&None), "");
+    }
+}
@@ -0,0 +1,129 @@
+//! The theme switcher: the set of shipped themes, the settings page itself,
+//! and the `<link>`/script wiring `ItemHeader` and `ItemBody` need to offer
+//! it on every page.
+
+use crate::{doc::module::ModuleInfo, render::Renderable, RenderPlan};
+use anyhow::Result;
+use horrorshow::{box_html, RenderBox};
+
+/// A shipped theme. The first variant is the default, applied when a reader
+/// has no saved preference yet.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Theme {
+    Ayu,
+    Dark,
+    Light,
+}
+impl Theme {
+    pub(crate) const ALL: [Theme; 3] = [Theme::Ayu, Theme::Dark, Theme::Light];
+
+    /// The `id` given to this theme's `<link>` and the value stored in
+    /// `localStorage`.
+    pub(crate) fn id(self) -> &'static str {
+        match self {
+            Theme::Ayu => "ayu",
+            Theme::Dark => "dark",
+            Theme::Light => "light",
+        }
+    }
+
+    /// The stylesheet asset backing this theme, relative to the docs root.
+    pub(crate) fn css_file(self) -> &'static str {
+        match self {
+            Theme::Ayu => "assets/ayu.css",
+            Theme::Dark => "assets/dark.css",
+            Theme::Light => "assets/light.css",
+        }
+    }
+
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            Theme::Ayu => "Ayu",
+            Theme::Dark => "Dark",
+            Theme::Light => "Light",
+        }
+    }
+}
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::ALL[0]
+    }
+}
+
+/// One `<link rel="stylesheet">` per shipped theme, with only the default
+/// theme enabled; `storage.js` flips `disabled` based on the saved
+/// preference before first paint.
+pub(crate) fn theme_links(module_info: &ModuleInfo) -> Vec<(String, &'static str, bool)> {
+    Theme::ALL
+        .iter()
+        .map(|theme| {
+            let href = module_info.to_html_shorthand_path_string(theme.css_file());
+            (href, theme.id(), *theme != Theme::default())
+        })
+        .collect()
+}
+
+/// The settings page, linked to from the gear button in the header nav.
+#[derive(Clone, Debug)]
+pub(crate) struct SettingsPage {
+    pub(crate) module_info: ModuleInfo,
+}
+impl Renderable for SettingsPage {
+    /// Renders a complete `<html>` page, as [`sources::SourcePage::render`]
+    /// does. Unlike a source page, its `<head>` also carries the theme links
+    /// and `storage.js`, so the live preview stays in sync with whatever theme
+    /// is already saved; the body holds the settings form, plus `settings.js`
+    /// to wire up the radio buttons.
+    ///
+    /// [`sources::SourcePage::render`]: crate::render::sources::SourcePage::render
+    fn render(self, _render_plan: RenderPlan) -> Result<Box<dyn RenderBox>> {
+        let SettingsPage { module_info } = self;
+
+        let favicon = module_info.to_html_shorthand_path_string("assets/sway-logo.svg");
+        let normalize = module_info.to_html_shorthand_path_string("assets/normalize.css");
+        let swaydoc = module_info.to_html_shorthand_path_string("assets/swaydoc.css");
+        let ayu_hjs = module_info.to_html_shorthand_path_string("assets/ayu.min.css");
+        let storage_js = module_info.to_html_shorthand_path_string("assets/storage.js");
+        let settings_js = module_info.to_html_shorthand_path_string("assets/settings.js");
+        let theme_links = theme_links(&module_info);
+
+        Ok(box_html! {
+            html {
+                head {
+                    meta(charset="utf-8");
+                    title: "Settings - Sway";
+                    link(rel="icon", href=favicon);
+                    link(rel="stylesheet", type="text/css", href=normalize);
+                    link(rel="stylesheet", type="text/css", href=swaydoc, id="mainThemeStyle");
+                    @ for (href, id, disabled) in &theme_links {
+                        link(rel="stylesheet", type="text/css", href=href, id=format!("theme-{id}"), disabled?=*disabled);
+                    }
+                    link(rel="stylesheet", href=ayu_hjs);
+                    script(src=storage_js);
+                }
+                body(class="swaydoc settings-page") {
+                    div(id="settings", class="settings") {
+                        h1 { : "Settings" }
+                        div(class="setting-line") {
+                            div(class="radio-line") {
+                                span(class="setting-name") { : "Theme" }
+                                @ for theme in Theme::ALL.iter() {
+                                    label(class="toggle") {
+                                        input(
+                                            type="radio",
+                                            name="theme",
+                                            value=theme.id(),
+                                            checked?=(*theme == Theme::default())
+                                        );
+                                        : theme.label();
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    script(src=settings_js);
+                }
+            }
+        })
+    }
+}
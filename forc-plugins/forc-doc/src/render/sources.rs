@@ -0,0 +1,182 @@
+//! Renders browsable, syntax-highlighted copies of the Sway source files
+//! backing documented items, mirroring rustdoc's `src/` tree.
+
+use crate::{doc::module::ModuleInfo, render::Renderable, RenderPlan};
+use anyhow::Result;
+use horrorshow::{box_html, RenderBox};
+use sway_types::Span;
+
+/// The relative path, under the docs root, that a source file is rendered to.
+///
+/// e.g. a module at `my_project::my_module` backing `my_file.sw` renders to
+/// `src/my_project/my_module/my_file.sw.html`.
+fn source_file_path(module_info: &ModuleInfo, file_name: &str) -> String {
+    let module_path = module_info.location().replace("::", "/");
+    format!("src/{module_path}/{file_name}.html")
+}
+
+/// Resolves the `href` that a `TyDecl`'s "source" link should point at, for a
+/// `span` in the file `file_name`.
+///
+/// The returned href is relative to the page currently being rendered, via
+/// [`ModuleInfo::to_html_shorthand_path_string`]. Single-line spans link to a
+/// plain `#L<n>` anchor (which every rendered line has); multi-line spans
+/// link to `#L<start>-<end>`, which `source-highlight.js` parses on the
+/// target page to highlight and scroll to the whole range.
+pub(crate) fn source_href(module_info: &ModuleInfo, file_name: &str, span: &Span) -> String {
+    let (start, end) = line_range(span);
+    let path = source_file_path(module_info, file_name);
+    format!(
+        "{}#{}",
+        module_info.to_html_shorthand_path_string(&path),
+        line_anchor_fragment(start, end)
+    )
+}
+
+/// The `#L<n>` or `#L<start>-<end>` fragment for a line range, used by
+/// [`source_href`]; it matches the `L<n>` ids that [`SourcePage`] gives its
+/// per-line anchors.
+fn line_anchor_fragment(start: usize, end: usize) -> String {
+    if start == end {
+        format!("L{start}")
+    } else {
+        format!("L{start}-{end}")
+    }
+}
+
+/// The 1-indexed `(start, end)` line numbers a span covers within its source file.
+pub(crate) fn line_range(span: &Span) -> (usize, usize) {
+    line_range_in(span.src().text.as_str(), span.start(), span.end())
+}
+
+/// The 1-indexed `(start, end)` line numbers the byte range `[start_byte,
+/// end_byte)` covers within `source`. Split out from [`line_range`] so it can
+/// be unit-tested without needing a real `Span`.
+fn line_range_in(source: &str, start_byte: usize, end_byte: usize) -> (usize, usize) {
+    // Count newlines rather than `lines().count()`: the latter drops the line
+    // currently being started when `start_byte` sits right after a `\n`,
+    // undercounting by one.
+    let start_line = source[..start_byte].matches('\n').count() + 1;
+    let end_line = start_line + source[start_byte..end_byte].matches('\n').count();
+    (start_line, end_line)
+}
+
+/// A single rendered source file, split into per-line anchors ready to be
+/// written to `src/<module>/<file>.html`.
+#[derive(Clone, Debug)]
+pub(crate) struct SourcePage {
+    pub(crate) module_info: ModuleInfo,
+    pub(crate) file_name: String,
+    pub(crate) source: String,
+}
+impl SourcePage {
+    pub(crate) fn new(module_info: ModuleInfo, file_name: String, source: String) -> Self {
+        Self {
+            module_info,
+            file_name,
+            source,
+        }
+    }
+
+    /// The path this page should be written to, relative to the docs root.
+    pub(crate) fn output_path(&self) -> String {
+        source_file_path(&self.module_info, &self.file_name)
+    }
+}
+impl Renderable for SourcePage {
+    /// Renders a full, syntax-highlighted page: one `id="L<n>"` anchor per
+    /// line (so a single-line `#L<n>` href scrolls natively), plus
+    /// `source-highlight.js` to highlight and scroll to a `#L<start>-<end>`
+    /// range on load.
+    fn render(self, _render_plan: RenderPlan) -> Result<Box<dyn RenderBox>> {
+        let SourcePage {
+            module_info,
+            file_name,
+            source,
+        } = self;
+        let lines: Vec<String> = source.lines().map(str::to_string).collect();
+
+        let favicon = module_info.to_html_shorthand_path_string("assets/sway-logo.svg");
+        let normalize = module_info.to_html_shorthand_path_string("assets/normalize.css");
+        let swaydoc = module_info.to_html_shorthand_path_string("assets/swaydoc.css");
+        let sway_hjs = module_info.to_html_shorthand_path_string("assets/highlight.js");
+        let ayu_hjs = module_info.to_html_shorthand_path_string("assets/ayu.min.css");
+        let source_highlight_js =
+            module_info.to_html_shorthand_path_string("assets/source-highlight.js");
+
+        Ok(box_html! {
+            html {
+                head {
+                    meta(charset="utf-8");
+                    title: format!("{file_name} - source");
+                    link(rel="icon", href=favicon);
+                    link(rel="stylesheet", type="text/css", href=normalize);
+                    link(rel="stylesheet", type="text/css", href=swaydoc);
+                    link(rel="stylesheet", href=ayu_hjs);
+                }
+                body(class="swaydoc source-page") {
+                    div(class="source") {
+                        pre(class="src-line-numbers") {
+                            @ for (i, _) in lines.iter().enumerate() {
+                                a(id=format!("L{}", i + 1), href=format!("#L{}", i + 1)) {
+                                    : format!("{}", i + 1);
+                                }
+                            }
+                        }
+                        pre(class="src sway") {
+                            code(class="language-sway") {
+                                @ for line in &lines {
+                                    : line.clone();
+                                    : "\n";
+                                }
+                            }
+                        }
+                    }
+                    script(src=sway_hjs);
+                    script {
+                        : "hljs.highlightAll();";
+                    }
+                    script(src=source_highlight_js);
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_range_in_first_line() {
+        let source = "fn main() {}\nfn other() {}\n";
+        assert_eq!(line_range_in(source, 0, 5), (1, 1));
+    }
+
+    #[test]
+    fn line_range_in_at_line_boundary() {
+        // A span starting right after a `\n` used to be counted as still on
+        // the previous line, yielding the wrong anchor.
+        let source = "fn main() {}\nfn other() {}\n";
+        let start_byte = source.find("fn other").unwrap();
+        assert_eq!(line_range_in(source, start_byte, start_byte + 8), (2, 2));
+    }
+
+    #[test]
+    fn line_range_in_spans_multiple_lines() {
+        let source = "fn main() {\n    foo();\n    bar();\n}\n";
+        let start_byte = source.find("foo").unwrap();
+        let end_byte = source.find('}').unwrap();
+        assert_eq!(line_range_in(source, start_byte, end_byte), (2, 4));
+    }
+
+    #[test]
+    fn line_anchor_fragment_single_line() {
+        assert_eq!(line_anchor_fragment(3, 3), "L3");
+    }
+
+    #[test]
+    fn line_anchor_fragment_multi_line() {
+        assert_eq!(line_anchor_fragment(3, 7), "L3-7");
+    }
+}